@@ -15,6 +15,21 @@ pub struct LinuxProcessConfiguration {
     pub(crate) user_id: Option<u32>,
     pub(crate) group_id: Option<u32>,
     pub(crate) process_group_id: Option<u32>,
+    pub(crate) pty: Option<LinuxPtySize>,
+    pub(crate) stdin_chunk_size: usize,
+    pub(crate) max_output_capacity: Option<usize>,
+}
+
+/// Matches the chunk size distant's SSH handler uses to avoid overwhelming the SSH channel
+/// window with a single large write.
+pub const DEFAULT_STDIN_CHUNK_SIZE: usize = 8192;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinuxPtySize {
+    pub rows: u16,
+    pub cols: u16,
+    pub pixel_width: u16,
+    pub pixel_height: u16,
 }
 
 impl LinuxProcessConfiguration {
@@ -31,6 +46,9 @@ impl LinuxProcessConfiguration {
             user_id: None,
             group_id: None,
             process_group_id: None,
+            pty: None,
+            stdin_chunk_size: DEFAULT_STDIN_CHUNK_SIZE,
+            max_output_capacity: None,
         }
     }
 
@@ -98,6 +116,29 @@ impl LinuxProcessConfiguration {
         self.process_group_id = Some(process_group_id);
         self
     }
+
+    /// Allocates a pseudo-terminal for the process, merging stdout/stderr onto the PTY stream as
+    /// a real terminal would. Required for programs that refuse to run without a TTY (shells,
+    /// `top`, `sudo` with prompts, editors).
+    pub fn pty(&mut self, size: LinuxPtySize) -> &mut Self {
+        self.pty = Some(size);
+        self
+    }
+
+    /// Overrides the chunk size stdin writes are split into, respecting SSH channel flow
+    /// control instead of buffering an arbitrarily large write. Defaults to
+    /// `DEFAULT_STDIN_CHUNK_SIZE`.
+    pub fn stdin_chunk_size(&mut self, stdin_chunk_size: usize) -> &mut Self {
+        self.stdin_chunk_size = stdin_chunk_size;
+        self
+    }
+
+    /// Caps how many trailing bytes of stdout/stderr are retained in partial output; once
+    /// exceeded, older bytes are dropped and `LinuxProcessPartialOutput::truncated` is set.
+    pub fn max_output_capacity(&mut self, max_output_capacity: usize) -> &mut Self {
+        self.max_output_capacity = Some(max_output_capacity);
+        self
+    }
 }
 
 #[derive(Debug)]
@@ -118,6 +159,10 @@ pub struct LinuxProcessOutput {
     pub stderr: Option<Vec<u8>>,
     pub stdout_extended: HashMap<u32, Vec<u8>>,
     pub status_code: Option<i64>,
+    /// Set once `LinuxProcessConfiguration::max_output_capacity` caused older bytes to be
+    /// dropped from one of the buffers above, so a caller draining the final output can tell it
+    /// was capped.
+    pub truncated: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -125,6 +170,9 @@ pub struct LinuxProcessPartialOutput {
     pub stdout: Option<Vec<u8>>,
     pub stderr: Option<Vec<u8>>,
     pub stdout_extended: HashMap<u32, Vec<u8>>,
+    /// Set once `LinuxProcessConfiguration::max_output_capacity` caused older bytes to be
+    /// dropped from one of the buffers above.
+    pub truncated: bool,
 }
 
 #[async_trait]
@@ -133,6 +181,20 @@ pub trait LinuxProcess: Sized {
 
     async fn write_to_stdin(&mut self, data: &[u8]) -> Result<usize, LinuxProcessError>;
 
+    /// Loops over `write_to_stdin` until the entire slice has been flushed, so callers don't
+    /// have to handle short writes themselves.
+    async fn write_all_to_stdin(&mut self, data: &[u8]) -> Result<(), LinuxProcessError> {
+        let mut written = 0;
+        while written < data.len() {
+            let chunk_written = self.write_to_stdin(&data[written..]).await?;
+            if chunk_written == 0 {
+                return Err(LinuxProcessError::IO(std::io::Error::from(std::io::ErrorKind::WriteZero)));
+            }
+            written += chunk_written;
+        }
+        Ok(())
+    }
+
     async fn close_stdin(&mut self) -> Result<(), LinuxProcessError>;
 
     fn get_partial_output(&self) -> Result<LinuxProcessPartialOutput, LinuxProcessError>;
@@ -143,6 +205,12 @@ pub trait LinuxProcess: Sized {
 
     async fn begin_kill(&mut self) -> Result<(), LinuxProcessError>;
 
+    /// Resizes the process's pseudo-terminal. Returns `UnsupportedOperation` when the process
+    /// was not started with `LinuxProcessConfiguration::pty`.
+    async fn resize_pty(&mut self, _size: LinuxPtySize) -> Result<(), LinuxProcessError> {
+        Err(LinuxProcessError::UnsupportedOperation)
+    }
+
     async fn kill(&mut self) -> Result<Option<i64>, LinuxProcessError> {
         self.begin_kill().await?;
         self.await_exit().await
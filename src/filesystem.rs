@@ -1,10 +1,13 @@
 use std::{
+    ffi::{OsStr, OsString},
     fs::Permissions,
     io,
+    ops::{BitOr, BitOrAssign},
     path::{Path, PathBuf},
 };
 
 use async_trait::async_trait;
+use futures::Stream;
 use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 
 #[derive(Clone, Debug)]
@@ -73,6 +76,219 @@ impl LinuxOpenOptions {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LinuxChangeKind {
+    Create,
+    Modify,
+    Remove,
+    Rename,
+    Attribute,
+    Access,
+}
+
+/// A filter over `LinuxChangeKind`s, backed by a bitmask so a watch can be scoped to the kinds
+/// the caller actually cares about.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LinuxChangeKindSet(u8);
+
+impl LinuxChangeKindSet {
+    pub fn empty() -> LinuxChangeKindSet {
+        LinuxChangeKindSet(0)
+    }
+
+    pub fn all() -> LinuxChangeKindSet {
+        LinuxChangeKindSet::empty()
+            .with(LinuxChangeKind::Create)
+            .with(LinuxChangeKind::Modify)
+            .with(LinuxChangeKind::Remove)
+            .with(LinuxChangeKind::Rename)
+            .with(LinuxChangeKind::Attribute)
+            .with(LinuxChangeKind::Access)
+    }
+
+    pub fn with(mut self, kind: LinuxChangeKind) -> LinuxChangeKindSet {
+        self.0 |= 1 << kind as u8;
+        self
+    }
+
+    pub fn contains(&self, kind: LinuxChangeKind) -> bool {
+        self.0 & (1 << kind as u8) != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for LinuxChangeKindSet {
+    type Output = LinuxChangeKindSet;
+
+    fn bitor(self, rhs: LinuxChangeKindSet) -> LinuxChangeKindSet {
+        LinuxChangeKindSet(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for LinuxChangeKindSet {
+    fn bitor_assign(&mut self, rhs: LinuxChangeKindSet) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl From<LinuxChangeKind> for LinuxChangeKindSet {
+    fn from(kind: LinuxChangeKind) -> LinuxChangeKindSet {
+        LinuxChangeKindSet::empty().with(kind)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LinuxChangeEvent {
+    pub path: PathBuf,
+    pub kinds: LinuxChangeKindSet,
+}
+
+#[derive(Clone, Debug)]
+pub struct LinuxWatchOptions {
+    recursive: bool,
+    kinds: LinuxChangeKindSet,
+}
+
+impl LinuxWatchOptions {
+    pub fn new() -> LinuxWatchOptions {
+        LinuxWatchOptions {
+            recursive: false,
+            kinds: LinuxChangeKindSet::all(),
+        }
+    }
+
+    pub fn is_recursive(&self) -> bool {
+        self.recursive
+    }
+
+    pub fn kinds(&self) -> LinuxChangeKindSet {
+        self.kinds
+    }
+
+    pub fn recursive(&mut self) -> &mut LinuxWatchOptions {
+        self.recursive = true;
+        self
+    }
+
+    pub fn kind_filter(&mut self, kinds: LinuxChangeKindSet) -> &mut LinuxWatchOptions {
+        self.kinds = kinds;
+        self
+    }
+}
+
+impl Default for LinuxWatchOptions {
+    fn default() -> LinuxWatchOptions {
+        LinuxWatchOptions::new()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LinuxFileType {
+    File,
+    Dir,
+    Symlink,
+}
+
+#[derive(Clone, Debug)]
+pub struct LinuxDirEntry {
+    path: PathBuf,
+    name: OsString,
+    file_type: LinuxFileType,
+    depth: usize,
+}
+
+impl LinuxDirEntry {
+    pub(crate) fn new(path: PathBuf, name: OsString, file_type: LinuxFileType, depth: usize) -> LinuxDirEntry {
+        LinuxDirEntry {
+            path,
+            name,
+            file_type,
+            depth,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn name(&self) -> &OsStr {
+        &self.name
+    }
+
+    pub fn file_type(&self) -> LinuxFileType {
+        self.file_type
+    }
+
+    /// Depth of this entry relative to the walk's root (0 for the root itself).
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LinuxWalkOptions {
+    max_depth: Option<usize>,
+    min_depth: usize,
+    follow_symlinks: bool,
+    include_root: bool,
+}
+
+impl LinuxWalkOptions {
+    pub fn new() -> LinuxWalkOptions {
+        LinuxWalkOptions {
+            max_depth: None,
+            min_depth: 0,
+            follow_symlinks: false,
+            include_root: false,
+        }
+    }
+
+    pub fn max_depth_value(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    pub fn min_depth_value(&self) -> usize {
+        self.min_depth
+    }
+
+    pub fn follows_symlinks(&self) -> bool {
+        self.follow_symlinks
+    }
+
+    pub fn includes_root(&self) -> bool {
+        self.include_root
+    }
+
+    pub fn max_depth(&mut self, max_depth: usize) -> &mut LinuxWalkOptions {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    pub fn min_depth(&mut self, min_depth: usize) -> &mut LinuxWalkOptions {
+        self.min_depth = min_depth;
+        self
+    }
+
+    pub fn follow_symlinks(&mut self) -> &mut LinuxWalkOptions {
+        self.follow_symlinks = true;
+        self
+    }
+
+    pub fn include_root(&mut self) -> &mut LinuxWalkOptions {
+        self.include_root = true;
+        self
+    }
+}
+
+impl Default for LinuxWalkOptions {
+    fn default() -> LinuxWalkOptions {
+        LinuxWalkOptions::new()
+    }
+}
+
 #[async_trait]
 pub trait LinuxFilesystem {
     async fn exists(&self, path: &Path) -> io::Result<bool>;
@@ -98,4 +314,20 @@ pub trait LinuxFilesystem {
     async fn read_link(&self, link_path: &Path) -> io::Result<PathBuf>;
 
     async fn set_permissions(&self, path: &Path, permissions: Permissions) -> io::Result<()>;
+
+    /// Watches `path` for changes, yielding a `LinuxChangeEvent` per detected change. The stream
+    /// ends once the returned handle (or the stream itself) is dropped.
+    async fn watch(
+        &self,
+        path: &Path,
+        options: &LinuxWatchOptions,
+    ) -> io::Result<impl Stream<Item = LinuxChangeEvent>>;
+
+    /// Recursively walks `root`, streaming entries as directories are descended so enormous
+    /// trees don't have to be fully materialized in memory.
+    async fn walk_dir(
+        &self,
+        root: &Path,
+        options: &LinuxWalkOptions,
+    ) -> io::Result<impl Stream<Item = io::Result<LinuxDirEntry>>>;
 }
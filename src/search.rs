@@ -0,0 +1,99 @@
+use std::{io, path::PathBuf};
+
+use async_trait::async_trait;
+use futures::Stream;
+use tokio::sync::oneshot;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LinuxSearchTarget {
+    Path,
+    Contents,
+}
+
+#[derive(Clone, Debug)]
+pub struct LinuxSearchQuery {
+    pub(crate) root: PathBuf,
+    pub(crate) target: LinuxSearchTarget,
+    pub(crate) condition: String,
+    pub(crate) include_globs: Vec<String>,
+    pub(crate) exclude_globs: Vec<String>,
+    pub(crate) max_depth: Option<usize>,
+    pub(crate) follow_symlinks: bool,
+    pub(crate) limit: Option<u64>,
+}
+
+impl LinuxSearchQuery {
+    pub fn new(root: impl Into<PathBuf>, target: LinuxSearchTarget, condition: impl Into<String>) -> LinuxSearchQuery {
+        LinuxSearchQuery {
+            root: root.into(),
+            target,
+            condition: condition.into(),
+            include_globs: Vec::new(),
+            exclude_globs: Vec::new(),
+            max_depth: None,
+            follow_symlinks: false,
+            limit: None,
+        }
+    }
+
+    pub fn include(&mut self, glob: impl Into<String>) -> &mut LinuxSearchQuery {
+        self.include_globs.push(glob.into());
+        self
+    }
+
+    pub fn exclude(&mut self, glob: impl Into<String>) -> &mut LinuxSearchQuery {
+        self.exclude_globs.push(glob.into());
+        self
+    }
+
+    pub fn max_depth(&mut self, max_depth: usize) -> &mut LinuxSearchQuery {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    pub fn follow_symlinks(&mut self) -> &mut LinuxSearchQuery {
+        self.follow_symlinks = true;
+        self
+    }
+
+    pub fn limit(&mut self, limit: u64) -> &mut LinuxSearchQuery {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LinuxSearchMatch {
+    pub path: PathBuf,
+    pub line_number: Option<u64>,
+    pub matched_bytes: Vec<u8>,
+    pub submatches: Vec<(usize, usize)>,
+}
+
+/// A handle onto an in-flight remote search. Dropping it without calling `cancel` lets the
+/// search run to completion; calling `cancel` kills the underlying remote process.
+pub struct LinuxSearchId {
+    cancel_tx: Option<oneshot::Sender<()>>,
+}
+
+impl LinuxSearchId {
+    pub(crate) fn new(cancel_tx: oneshot::Sender<()>) -> LinuxSearchId {
+        LinuxSearchId {
+            cancel_tx: Some(cancel_tx),
+        }
+    }
+
+    pub fn cancel(mut self) {
+        if let Some(cancel_tx) = self.cancel_tx.take() {
+            let _ = cancel_tx.send(());
+        }
+    }
+}
+
+#[async_trait]
+pub trait LinuxSearch {
+    async fn search(
+        &self,
+        query: &LinuxSearchQuery,
+    ) -> io::Result<(LinuxSearchId, impl Stream<Item = io::Result<LinuxSearchMatch>>)>;
+}
@@ -0,0 +1,43 @@
+use std::collections::VecDeque;
+
+/// Accumulates process output bytes, retaining only the last `capacity` bytes once a cap is
+/// configured. Used by the russh process implementation to back `LinuxProcessPartialOutput`
+/// without risking unbounded memory growth on long-running, noisy processes. Backed by a
+/// `VecDeque` so trimming old bytes is an O(1) pop per byte rather than a memmove of the whole
+/// retained buffer.
+#[derive(Debug, Default)]
+pub(crate) struct OutputBuffer {
+    capacity: Option<usize>,
+    bytes: VecDeque<u8>,
+    truncated: bool,
+}
+
+impl OutputBuffer {
+    pub(crate) fn new(capacity: Option<usize>) -> OutputBuffer {
+        OutputBuffer {
+            capacity,
+            bytes: VecDeque::new(),
+            truncated: false,
+        }
+    }
+
+    pub(crate) fn push(&mut self, data: &[u8]) {
+        self.bytes.extend(data.iter().copied());
+
+        if let Some(capacity) = self.capacity {
+            if self.bytes.len() > capacity {
+                let overflow = self.bytes.len() - capacity;
+                self.bytes.drain(0..overflow);
+                self.truncated = true;
+            }
+        }
+    }
+
+    pub(crate) fn snapshot(&mut self) -> &[u8] {
+        self.bytes.make_contiguous()
+    }
+
+    pub(crate) fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
@@ -0,0 +1,41 @@
+use russh::{Channel, ChannelMsg};
+
+use crate::executor::{LinuxPtySize, LinuxProcessError};
+
+const TERM: &str = "xterm";
+
+/// Requests a pseudo-terminal on `channel` before the process is exec'd, matching the size
+/// configured via `LinuxProcessConfiguration::pty`.
+pub(crate) async fn request_pty<S>(channel: &mut Channel<S>, size: LinuxPtySize) -> Result<(), LinuxProcessError>
+where
+    S: From<ChannelMsg> + Send + 'static,
+{
+    channel
+        .request_pty(
+            false,
+            TERM,
+            size.cols as u32,
+            size.rows as u32,
+            size.pixel_width as u32,
+            size.pixel_height as u32,
+            &[],
+        )
+        .await
+        .map_err(|error| LinuxProcessError::Other(Box::new(error)))
+}
+
+/// Sends a `window-change` request for an already-allocated pseudo-terminal.
+pub(crate) async fn resize_pty<S>(channel: &Channel<S>, size: LinuxPtySize) -> Result<(), LinuxProcessError>
+where
+    S: From<ChannelMsg> + Send + 'static,
+{
+    channel
+        .window_change(
+            size.cols as u32,
+            size.rows as u32,
+            size.pixel_width as u32,
+            size.pixel_height as u32,
+        )
+        .await
+        .map_err(|error| LinuxProcessError::Other(Box::new(error)))
+}
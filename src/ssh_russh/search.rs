@@ -0,0 +1,160 @@
+use std::io;
+
+use async_trait::async_trait;
+use futures::Stream;
+use russh::client;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{
+    executor::{LinuxExecutor, LinuxProcess, LinuxProcessConfiguration},
+    search::{LinuxSearch, LinuxSearchId, LinuxSearchMatch, LinuxSearchQuery, LinuxSearchTarget},
+};
+
+use super::{stdout_drain::StdoutDrain, RusshLinux};
+
+/// `LinuxProcessConfiguration::arg` appends one argv entry, exec'd without going through a shell
+/// (mirroring `std::process::Command::arg`), so regex/glob values are passed through verbatim
+/// rather than shell-quoted.
+fn build_configuration(query: &LinuxSearchQuery) -> LinuxProcessConfiguration {
+    match query.target {
+        LinuxSearchTarget::Contents => {
+            // POSIX extended regex (`-E`), matching the `-regextype posix-extended` flavor used
+            // by the path branch below, and case-sensitive like `find -regex`.
+            let mut configuration = LinuxProcessConfiguration::new("grep");
+            configuration.arg("-rnE").redirect_stdout();
+            for glob in &query.include_globs {
+                configuration.arg("--include").arg(glob);
+            }
+            for glob in &query.exclude_globs {
+                configuration.arg("--exclude").arg(glob);
+            }
+            configuration.arg(query.condition.as_str());
+            configuration.arg(query.root.to_string_lossy().to_string());
+            configuration
+        }
+        LinuxSearchTarget::Path => {
+            let mut configuration = LinuxProcessConfiguration::new("find");
+            // See `walk::build_configuration`: `-L` must precede the path operand.
+            if query.follow_symlinks {
+                configuration.arg("-L");
+            }
+            configuration.arg(query.root.to_string_lossy().to_string());
+            if let Some(max_depth) = query.max_depth {
+                configuration.arg("-maxdepth").arg(max_depth.to_string());
+            }
+            configuration
+                .arg("-regextype")
+                .arg("posix-extended")
+                .arg("-regex")
+                .arg(query.condition.as_str())
+                .redirect_stdout();
+            configuration
+        }
+    }
+}
+
+fn parse_contents_line(line: &str) -> Option<LinuxSearchMatch> {
+    let mut parts = line.splitn(3, ':');
+    let path = parts.next()?;
+    let line_number: u64 = parts.next()?.parse().ok()?;
+    let content = parts.next()?;
+    Some(LinuxSearchMatch {
+        path: path.into(),
+        line_number: Some(line_number),
+        matched_bytes: content.as_bytes().to_vec(),
+        submatches: Vec::new(),
+    })
+}
+
+fn parse_path_line(line: &str) -> LinuxSearchMatch {
+    LinuxSearchMatch {
+        path: line.into(),
+        line_number: None,
+        matched_bytes: line.as_bytes().to_vec(),
+        submatches: Vec::new(),
+    }
+}
+
+async fn drive_search<P>(
+    mut process: P,
+    target: LinuxSearchTarget,
+    limit: Option<u64>,
+    tx: mpsc::UnboundedSender<io::Result<LinuxSearchMatch>>,
+    mut cancel_rx: oneshot::Receiver<()>,
+) where
+    P: LinuxProcess,
+{
+    let mut emitted: u64 = 0;
+    let mut drain = StdoutDrain::new();
+    let mut interval = tokio::time::interval(std::time::Duration::from_millis(50));
+    let mut cancel_pending = true;
+
+    loop {
+        tokio::select! {
+            cancelled = &mut cancel_rx, if cancel_pending => {
+                // A dropped `LinuxSearchId` closes the channel without cancelling the search.
+                if cancelled.is_ok() {
+                    let _ = process.begin_kill().await;
+                    return;
+                }
+                cancel_pending = false;
+            }
+            _ = interval.tick() => {}
+        }
+
+        // Checked before reading the output so lines written just before exit are still drained.
+        let exited = process.await_exit().await.ok().flatten().is_some();
+        let Ok(partial) = process.get_partial_output() else {
+            return;
+        };
+        if let Some(fresh) = drain.poll(&partial) {
+            for line in fresh.lines() {
+                let found = match target {
+                    LinuxSearchTarget::Contents => parse_contents_line(line),
+                    LinuxSearchTarget::Path => Some(parse_path_line(line)),
+                };
+                if let Some(found) = found {
+                    if tx.send(Ok(found)).is_err() {
+                        let _ = process.begin_kill().await;
+                        return;
+                    }
+                    emitted += 1;
+                    if let Some(limit) = limit {
+                        if emitted >= limit {
+                            let _ = process.begin_kill().await;
+                            return;
+                        }
+                    }
+                }
+            }
+        }
+
+        if exited {
+            return;
+        }
+    }
+}
+
+#[async_trait]
+impl<H> LinuxSearch for RusshLinux<H>
+where
+    H: client::Handler,
+    RusshLinux<H>: LinuxExecutor,
+{
+    async fn search(
+        &self,
+        query: &LinuxSearchQuery,
+    ) -> io::Result<(LinuxSearchId, impl Stream<Item = io::Result<LinuxSearchMatch>>)> {
+        let configuration = build_configuration(query);
+        let process = self
+            .begin_execute(&configuration)
+            .await
+            .map_err(|error| io::Error::other(format!("{error:?}")))?;
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let (cancel_tx, cancel_rx) = oneshot::channel();
+        tokio::spawn(drive_search(process, query.target, query.limit, tx, cancel_rx));
+
+        Ok((LinuxSearchId::new(cancel_tx), tokio_stream::wrappers::UnboundedReceiverStream::new(rx)))
+    }
+}
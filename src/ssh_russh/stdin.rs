@@ -0,0 +1,26 @@
+use russh::{Channel, ChannelMsg};
+
+use crate::executor::LinuxProcessError;
+
+/// Writes `data` to `channel` in `chunk_size`-sized pieces. Each chunk goes through
+/// `Channel::data`, which suspends until the SSH channel has window credit to accept it — so a
+/// large write backs off on the channel's flow control instead of being buffered client-side in
+/// one shot.
+pub(crate) async fn write_stdin_chunked<S>(
+    channel: &Channel<S>,
+    data: &[u8],
+    chunk_size: usize,
+) -> Result<usize, LinuxProcessError>
+where
+    S: From<ChannelMsg> + Send + 'static,
+{
+    let mut written = 0;
+    for chunk in data.chunks(chunk_size.max(1)) {
+        channel
+            .data(chunk)
+            .await
+            .map_err(|error| LinuxProcessError::Other(Box::new(error)))?;
+        written += chunk.len();
+    }
+    Ok(written)
+}
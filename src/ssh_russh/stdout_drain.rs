@@ -0,0 +1,27 @@
+use crate::executor::LinuxProcessPartialOutput;
+
+/// Tracks how much of a `LinuxProcess`'s accumulated stdout has already been consumed, since
+/// `get_partial_output` returns the *full* buffer on every call rather than just what's new.
+/// Shared by the `watch`, `walk_dir` and `search` drivers, which all poll a running process on a
+/// short interval and only want to act on lines that arrived since the last tick.
+#[derive(Debug, Default)]
+pub(crate) struct StdoutDrain {
+    consumed_len: usize,
+}
+
+impl StdoutDrain {
+    pub(crate) fn new() -> StdoutDrain {
+        StdoutDrain::default()
+    }
+
+    /// Returns the complete lines that arrived since the last call, or `None` if no new line has
+    /// been terminated yet. A trailing partial line is held back until its newline arrives.
+    pub(crate) fn poll(&mut self, partial: &LinuxProcessPartialOutput) -> Option<String> {
+        let stdout = partial.stdout.as_ref()?;
+        let unread = stdout.get(self.consumed_len..)?;
+        let complete_len = unread.iter().rposition(|byte| *byte == b'\n')? + 1;
+        let fresh = String::from_utf8_lossy(&unread[..complete_len]).into_owned();
+        self.consumed_len += complete_len;
+        Some(fresh)
+    }
+}
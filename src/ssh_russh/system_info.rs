@@ -0,0 +1,148 @@
+use std::io;
+
+use async_trait::async_trait;
+use russh::{client, ChannelMsg};
+use tokio::sync::OnceCell;
+
+use crate::{
+    executor::{LinuxExecutor, LinuxProcessConfiguration, LinuxPtySize},
+    system_info::{LinuxCapabilities, LinuxDiagnostics, LinuxSystemInfo},
+};
+
+use super::RusshLinux;
+
+const CAPABILITY_PROBE_PTY_SIZE: LinuxPtySize = LinuxPtySize {
+    rows: 24,
+    cols: 80,
+    pixel_width: 0,
+    pixel_height: 0,
+};
+
+async fn run(linux: &impl LinuxExecutor, program: &str, args: &[&str]) -> io::Result<String> {
+    let mut configuration = LinuxProcessConfiguration::new(program);
+    for arg in args {
+        configuration.arg(*arg);
+    }
+    configuration.redirect_stdout();
+
+    let output = linux
+        .execute(&configuration)
+        .await
+        .map_err(|error| io::Error::other(format!("{error:?}")))?;
+    Ok(String::from_utf8_lossy(&output.stdout.unwrap_or_default())
+        .trim()
+        .to_string())
+}
+
+async fn has_binary(linux: &impl LinuxExecutor, program: &str) -> bool {
+    let mut probe = LinuxProcessConfiguration::new("which");
+    probe.arg(program);
+    linux
+        .execute(&probe)
+        .await
+        .map(|output| output.status_code == Some(0))
+        .unwrap_or(false)
+}
+
+/// Probes real connection state rather than reporting a fixed answer: helper binary presence for
+/// `watch`/`search`, and a throwaway PTY allocation / reverse-forward request (immediately torn
+/// down) for `pty`/`reverse_forward_tcp`.
+async fn probe_capabilities<H>(linux: &RusshLinux<H>) -> LinuxCapabilities
+where
+    H: client::Handler,
+    RusshLinux<H>: LinuxExecutor,
+{
+    let has_inotifywait = has_binary(linux, "inotifywait").await;
+    let has_find = has_binary(linux, "find").await;
+    let has_grep = has_binary(linux, "grep").await;
+
+    let pty = {
+        let mut handle = linux.handle_mutex.lock().await;
+        match handle.channel_open_session().await {
+            Ok(mut channel) => {
+                // Asks for a reply and waits for it, so a server refusing the PTY reports `false`.
+                let size = CAPABILITY_PROBE_PTY_SIZE;
+                let mut supported = channel
+                    .request_pty(true, "xterm", size.cols as u32, size.rows as u32, 0, 0, &[])
+                    .await
+                    .is_ok();
+                while supported {
+                    match channel.wait().await {
+                        Some(ChannelMsg::Success) => break,
+                        Some(ChannelMsg::Failure) | None => supported = false,
+                        Some(_) => {}
+                    }
+                }
+                let _ = channel.close().await;
+                supported
+            }
+            Err(_) => false,
+        }
+    };
+
+    let reverse_forward_tcp = {
+        let mut handle = linux.handle_mutex.lock().await;
+        // `tcpip_forward` with port 0 asks the server to pick an ephemeral port and returns the
+        // one it bound; cancelling port 0 instead of that port would leave the forward running.
+        match handle.tcpip_forward("127.0.0.1", 0).await {
+            Ok(bound_port) => {
+                let _ = handle.cancel_tcpip_forward("127.0.0.1", bound_port).await;
+                true
+            }
+            Err(_) => false,
+        }
+    };
+
+    LinuxCapabilities {
+        reverse_forward_tcp,
+        pty,
+        watch: has_inotifywait || has_find,
+        search: has_grep && has_find,
+    }
+}
+
+async fn probe_system_info<H>(linux: &RusshLinux<H>) -> io::Result<LinuxSystemInfo>
+where
+    H: client::Handler,
+    RusshLinux<H>: LinuxExecutor,
+{
+    let uname = run(linux, "uname", &["-sm"]).await?;
+    let (os, arch) = uname.split_once(' ').unwrap_or((uname.as_str(), ""));
+    let current_dir = run(linux, "pwd", &[]).await?;
+    let username = run(linux, "whoami", &[]).await?;
+    // `sh -c 'echo $SHELL'` runs non-interactively, where `$SHELL` is usually unset; read the
+    // configured login shell from the passwd entry instead.
+    let passwd_entry = run(linux, "sh", &["-c", "getent passwd \"$(whoami)\""]).await?;
+    let shell = passwd_entry.rsplit(':').next().unwrap_or_default().to_string();
+
+    Ok(LinuxSystemInfo {
+        family: "unix".to_string(),
+        os: os.to_string(),
+        arch: arch.to_string(),
+        current_dir: current_dir.into(),
+        main_separator: '/',
+        username,
+        shell,
+    })
+}
+
+#[async_trait]
+impl<H> LinuxDiagnostics for RusshLinux<H>
+where
+    H: client::Handler,
+    RusshLinux<H>: LinuxExecutor,
+{
+    async fn system_info(&self) -> io::Result<LinuxSystemInfo> {
+        self.system_info_cache
+            .get_or_try_init(|| probe_system_info(self))
+            .await
+            .cloned()
+    }
+
+    async fn capabilities(&self) -> LinuxCapabilities {
+        *self.capabilities_cache.get_or_init(|| probe_capabilities(self)).await
+    }
+}
+
+pub(crate) type SystemInfoCache = OnceCell<LinuxSystemInfo>;
+pub(crate) type CapabilitiesCache = OnceCell<LinuxCapabilities>;
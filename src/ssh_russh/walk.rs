@@ -0,0 +1,148 @@
+use std::{
+    collections::HashSet,
+    io,
+    path::{Path, PathBuf},
+};
+
+use russh::client;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+use crate::{
+    executor::{LinuxExecutor, LinuxProcess, LinuxProcessConfiguration},
+    filesystem::{LinuxDirEntry, LinuxFileType, LinuxFilesystem, LinuxWalkOptions},
+};
+
+use super::{stdout_drain::StdoutDrain, RusshLinux};
+
+fn build_configuration(root: &Path, options: &LinuxWalkOptions) -> LinuxProcessConfiguration {
+    let mut configuration = LinuxProcessConfiguration::new("find");
+    // `-L`/`-H`/`-P` are global options and must precede the path operand.
+    if options.follows_symlinks() {
+        configuration.arg("-L");
+    }
+    configuration.arg(root.to_string_lossy().to_string());
+    if let Some(max_depth) = options.max_depth_value() {
+        configuration.arg("-maxdepth").arg(max_depth.to_string());
+    }
+    if options.min_depth_value() > 0 {
+        configuration.arg("-mindepth").arg(options.min_depth_value().to_string());
+    }
+    configuration.arg("-printf").arg("%y|%d|%p\\n").redirect_stdout();
+    configuration
+}
+
+fn parse_entry(line: &str, root: &Path) -> Option<LinuxDirEntry> {
+    let mut parts = line.splitn(3, '|');
+    let kind = parts.next()?;
+    let depth: usize = parts.next()?.parse().ok()?;
+    let path = PathBuf::from(parts.next()?);
+
+    if path == *root && depth == 0 {
+        return None;
+    }
+
+    let file_type = match kind {
+        "d" => LinuxFileType::Dir,
+        "l" => LinuxFileType::Symlink,
+        _ => LinuxFileType::File,
+    };
+    let name = path.file_name()?.to_os_string();
+    Some(LinuxDirEntry::new(path, name, file_type, depth))
+}
+
+/// Drains `find`'s output as it arrives (rather than waiting for the whole process to finish)
+/// so an enormous tree doesn't have to be materialized in memory before the first entry is
+/// yielded, guarding against symlink loops by tracking visited canonicalized paths.
+async fn drive_walk<H, P>(
+    linux: RusshLinux<H>,
+    mut process: P,
+    root: PathBuf,
+    follow_symlinks: bool,
+    include_root: bool,
+    tx: tokio::sync::mpsc::UnboundedSender<io::Result<LinuxDirEntry>>,
+) where
+    H: client::Handler,
+    RusshLinux<H>: LinuxFilesystem,
+    P: LinuxProcess,
+{
+    let mut visited: HashSet<PathBuf> = HashSet::new();
+    let mut drain = StdoutDrain::new();
+    let mut interval = tokio::time::interval(std::time::Duration::from_millis(50));
+
+    if include_root {
+        if let Some(name) = root.file_name() {
+            if tx
+                .send(Ok(LinuxDirEntry::new(root.clone(), name.to_os_string(), LinuxFileType::Dir, 0)))
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+
+    loop {
+        interval.tick().await;
+
+        // Checked before reading the output so entries written just before exit are still drained.
+        let exited = process.await_exit().await.ok().flatten().is_some();
+        let Ok(partial) = process.get_partial_output() else {
+            return;
+        };
+        if let Some(fresh) = drain.poll(&partial) {
+            for line in fresh.lines() {
+                let Some(entry) = parse_entry(line, &root) else {
+                    continue;
+                };
+
+                if follow_symlinks && entry.file_type() == LinuxFileType::Symlink {
+                    match linux.canonicalize(entry.path()).await {
+                        Ok(canonical) if !visited.insert(canonical) => continue,
+                        Ok(_) => {}
+                        Err(error) => {
+                            if tx.send(Err(error)).is_err() {
+                                return;
+                            }
+                            continue;
+                        }
+                    }
+                }
+
+                if tx.send(Ok(entry)).is_err() {
+                    return;
+                }
+            }
+        }
+
+        if exited {
+            return;
+        }
+    }
+}
+
+/// Streams a remote recursive directory walk by shelling out to `find`, guarding against
+/// symlink loops (when `follow_symlinks` is set) by tracking visited canonicalized paths.
+pub(crate) async fn walk_dir<H>(
+    linux: &RusshLinux<H>,
+    root: &std::path::Path,
+    options: &LinuxWalkOptions,
+) -> io::Result<UnboundedReceiverStream<io::Result<LinuxDirEntry>>>
+where
+    H: client::Handler,
+    RusshLinux<H>: LinuxExecutor + LinuxFilesystem + Clone + Send + Sync + 'static,
+{
+    let root = root.to_path_buf();
+    let configuration = build_configuration(&root, options);
+    let process = linux
+        .begin_execute(&configuration)
+        .await
+        .map_err(|error| io::Error::other(format!("{error:?}")))?;
+
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    let follow_symlinks = options.follows_symlinks();
+    let include_root = options.includes_root();
+    let linux = linux.clone();
+
+    tokio::spawn(drive_walk(linux, process, root, follow_symlinks, include_root, tx));
+
+    Ok(UnboundedReceiverStream::new(rx))
+}
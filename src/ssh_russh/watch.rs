@@ -0,0 +1,325 @@
+use std::{
+    collections::HashMap,
+    io,
+    path::{Path, PathBuf},
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::Stream;
+use russh::client;
+use tokio::sync::mpsc;
+
+use crate::{
+    executor::{LinuxExecutor, LinuxProcess, LinuxProcessConfiguration},
+    filesystem::{LinuxChangeEvent, LinuxChangeKind, LinuxChangeKindSet, LinuxWatchOptions},
+};
+
+use super::{stdout_drain::StdoutDrain, RusshLinux};
+
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(200);
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A live handle onto a remote `inotifywait` (or polling) helper process, yielding coalesced
+/// `LinuxChangeEvent`s until dropped.
+pub struct RusshWatchStream {
+    events_rx: mpsc::UnboundedReceiver<LinuxChangeEvent>,
+}
+
+impl Stream for RusshWatchStream {
+    type Item = LinuxChangeEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.events_rx.poll_recv(cx)
+    }
+}
+
+fn parse_inotify_line(line: &str) -> Option<(PathBuf, LinuxChangeKindSet)> {
+    // `inotifywait -m -r --format '%w%f|%e'` framing: `<absolute path>|<comma-separated events>`
+    let (path, events) = line.split_once('|')?;
+
+    let mut kinds = LinuxChangeKindSet::empty();
+    for event in events.split(',') {
+        let kind = match event {
+            "CREATE" => LinuxChangeKind::Create,
+            "MODIFY" | "CLOSE_WRITE" => LinuxChangeKind::Modify,
+            "DELETE" | "DELETE_SELF" => LinuxChangeKind::Remove,
+            "MOVED_FROM" | "MOVED_TO" | "MOVE_SELF" => LinuxChangeKind::Rename,
+            "ATTRIB" => LinuxChangeKind::Attribute,
+            "ACCESS" | "OPEN" => LinuxChangeKind::Access,
+            _ => continue,
+        };
+        kinds |= kind.into();
+    }
+
+    if kinds.is_empty() {
+        None
+    } else {
+        Some((PathBuf::from(path), kinds))
+    }
+}
+
+/// Diffs two `find -printf`-derived snapshots to approximate change events. This fallback only
+/// ever runs when `inotifywait` is unavailable (see `has_inotifywait`), and it can only ever
+/// report `Create`/`Modify`/`Remove`: `Rename`, `Attribute` and `Access` have no observable trace
+/// in a path+mtime snapshot, so a caller filtering on those kinds will see nothing while polling
+/// is in effect.
+fn diff_poll_snapshot(
+    previous: &HashMap<PathBuf, std::time::SystemTime>,
+    current: &HashMap<PathBuf, std::time::SystemTime>,
+    kinds: LinuxChangeKindSet,
+) -> Vec<(PathBuf, LinuxChangeKindSet)> {
+    let mut changes = Vec::new();
+    if kinds.contains(LinuxChangeKind::Create) || kinds.contains(LinuxChangeKind::Modify) {
+        for (path, modified_at) in current {
+            match previous.get(path) {
+                None if kinds.contains(LinuxChangeKind::Create) => {
+                    changes.push((path.clone(), LinuxChangeKind::Create.into()))
+                }
+                Some(previous_modified_at) if previous_modified_at != modified_at && kinds.contains(LinuxChangeKind::Modify) => {
+                    changes.push((path.clone(), LinuxChangeKind::Modify.into()))
+                }
+                _ => {}
+            }
+        }
+    }
+    if kinds.contains(LinuxChangeKind::Remove) {
+        for path in previous.keys() {
+            if !current.contains_key(path) {
+                changes.push((path.clone(), LinuxChangeKind::Remove.into()));
+            }
+        }
+    }
+    changes
+}
+
+fn parse_poll_snapshot(stdout: &[u8]) -> HashMap<PathBuf, std::time::SystemTime> {
+    let mut snapshot = HashMap::new();
+    for line in String::from_utf8_lossy(stdout).lines() {
+        let Some((path, timestamp)) = line.split_once('|') else {
+            continue;
+        };
+        let Ok(seconds) = timestamp.parse::<f64>() else {
+            continue;
+        };
+        let modified_at = std::time::UNIX_EPOCH + Duration::from_secs_f64(seconds);
+        snapshot.insert(PathBuf::from(path), modified_at);
+    }
+    snapshot
+}
+
+const ALL_CHANGE_KINDS: [LinuxChangeKind; 6] = [
+    LinuxChangeKind::Create,
+    LinuxChangeKind::Modify,
+    LinuxChangeKind::Remove,
+    LinuxChangeKind::Rename,
+    LinuxChangeKind::Attribute,
+    LinuxChangeKind::Access,
+];
+
+fn intersect_kinds(a: LinuxChangeKindSet, b: LinuxChangeKindSet) -> LinuxChangeKindSet {
+    ALL_CHANGE_KINDS
+        .into_iter()
+        .filter(|kind| a.contains(*kind) && b.contains(*kind))
+        .fold(LinuxChangeKindSet::empty(), |set, kind| set | kind.into())
+}
+
+/// `inotifywait -e` event names that back each `LinuxChangeKind`, so the kind filter is enforced
+/// remotely instead of every kind being reported regardless of what the caller asked for.
+fn inotify_event_names(kind: LinuxChangeKind) -> &'static [&'static str] {
+    match kind {
+        LinuxChangeKind::Create => &["create"],
+        LinuxChangeKind::Modify => &["modify", "close_write"],
+        LinuxChangeKind::Remove => &["delete", "delete_self"],
+        LinuxChangeKind::Rename => &["moved_from", "moved_to", "move_self"],
+        LinuxChangeKind::Attribute => &["attrib"],
+        LinuxChangeKind::Access => &["access", "open"],
+    }
+}
+
+fn inotifywait_configuration(path: &Path, options: &LinuxWatchOptions) -> LinuxProcessConfiguration {
+    let mut configuration = LinuxProcessConfiguration::new("inotifywait");
+    configuration.arg("-m").arg("--format").arg("%w%f|%e");
+    if options.is_recursive() {
+        configuration.arg("-r");
+    }
+    for kind in ALL_CHANGE_KINDS {
+        if options.kinds().contains(kind) {
+            for event_name in inotify_event_names(kind) {
+                configuration.arg("-e").arg(*event_name);
+            }
+        }
+    }
+    configuration.arg(path.to_string_lossy().to_string()).redirect_stdout();
+    configuration
+}
+
+fn polling_configuration(path: &Path, options: &LinuxWatchOptions) -> LinuxProcessConfiguration {
+    let mut configuration = LinuxProcessConfiguration::new("find");
+    configuration.arg(path.to_string_lossy().to_string());
+    if !options.is_recursive() {
+        configuration.arg("-maxdepth").arg("1");
+    }
+    configuration.arg("-printf").arg("%p|%T@\\n").redirect_stdout();
+    configuration
+}
+
+/// Coalesces per-path change kinds within `DEBOUNCE_WINDOW` before they're emitted, so a burst of
+/// events for the same path (e.g. several writes during a save) surfaces as one
+/// `LinuxChangeEvent`. Shared by the `inotifywait` and polling drivers so both backends behave
+/// the same way from a caller's perspective.
+struct Debouncer {
+    pending: HashMap<PathBuf, (LinuxChangeKindSet, tokio::time::Instant)>,
+}
+
+impl Debouncer {
+    fn new() -> Debouncer {
+        Debouncer { pending: HashMap::new() }
+    }
+
+    fn record(&mut self, path: PathBuf, kinds: LinuxChangeKindSet) {
+        let entry = self
+            .pending
+            .entry(path)
+            .or_insert_with(|| (LinuxChangeKindSet::empty(), tokio::time::Instant::now()));
+        entry.0 |= kinds;
+        entry.1 = tokio::time::Instant::now();
+    }
+
+    fn drain_ready(&mut self) -> Vec<(PathBuf, LinuxChangeKindSet)> {
+        let now = tokio::time::Instant::now();
+        let ready: Vec<PathBuf> = self
+            .pending
+            .iter()
+            .filter(|(_, (_, seen_at))| now.duration_since(*seen_at) >= DEBOUNCE_WINDOW)
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        ready
+            .into_iter()
+            .filter_map(|path| self.pending.remove(&path).map(|(kinds, _)| (path, kinds)))
+            .collect()
+    }
+}
+
+async fn has_inotifywait<H>(linux: &RusshLinux<H>) -> bool
+where
+    H: client::Handler,
+    RusshLinux<H>: LinuxExecutor,
+{
+    let mut probe = LinuxProcessConfiguration::new("which");
+    probe.arg("inotifywait");
+    linux
+        .execute(&probe)
+        .await
+        .map(|output| output.status_code == Some(0))
+        .unwrap_or(false)
+}
+
+/// Drives the remote watcher, emitting debounced `LinuxChangeEvent`s over `tx` until the
+/// underlying process ends or the receiving stream is dropped.
+async fn drive_inotifywait<P>(mut process: P, kind_filter: LinuxChangeKindSet, tx: mpsc::UnboundedSender<LinuxChangeEvent>)
+where
+    P: LinuxProcess,
+{
+    let mut debouncer = Debouncer::new();
+    let mut drain = StdoutDrain::new();
+    let mut interval = tokio::time::interval(Duration::from_millis(50));
+
+    loop {
+        interval.tick().await;
+
+        if let Ok(partial) = process.get_partial_output() {
+            if let Some(fresh) = drain.poll(&partial) {
+                for line in fresh.lines() {
+                    let Some((path, line_kinds)) = parse_inotify_line(line) else {
+                        continue;
+                    };
+                    let filtered_kinds = intersect_kinds(line_kinds, kind_filter);
+                    if filtered_kinds.is_empty() {
+                        continue;
+                    }
+                    debouncer.record(path, filtered_kinds);
+                }
+            }
+        }
+
+        for (path, kinds) in debouncer.drain_ready() {
+            if tx.send(LinuxChangeEvent { path, kinds }).is_err() {
+                return;
+            }
+        }
+
+        if process.await_exit().await.ok().flatten().is_some() {
+            return;
+        }
+    }
+}
+
+async fn drive_polling<H>(
+    linux: RusshLinux<H>,
+    configuration: LinuxProcessConfiguration,
+    kind_filter: LinuxChangeKindSet,
+    tx: mpsc::UnboundedSender<LinuxChangeEvent>,
+) where
+    H: client::Handler,
+    RusshLinux<H>: LinuxExecutor,
+{
+    let mut debouncer = Debouncer::new();
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+
+    // Seed `previous` with the first snapshot instead of diffing against an empty map, which
+    // would otherwise report every pre-existing path as a `Create` on startup.
+    let Ok(output) = linux.execute(&configuration).await else {
+        return;
+    };
+    let mut previous = parse_poll_snapshot(&output.stdout.unwrap_or_default());
+
+    loop {
+        interval.tick().await;
+
+        let Ok(output) = linux.execute(&configuration).await else {
+            return;
+        };
+        let Some(stdout) = output.stdout else {
+            continue;
+        };
+        let current = parse_poll_snapshot(&stdout);
+
+        for (path, kinds) in diff_poll_snapshot(&previous, &current, kind_filter) {
+            debouncer.record(path, kinds);
+        }
+        for (path, kinds) in debouncer.drain_ready() {
+            if tx.send(LinuxChangeEvent { path, kinds }).is_err() {
+                return;
+            }
+        }
+
+        previous = current;
+    }
+}
+
+pub(crate) async fn spawn_watch<H>(linux: &RusshLinux<H>, path: &Path, options: &LinuxWatchOptions) -> io::Result<RusshWatchStream>
+where
+    H: client::Handler + Clone + Send + 'static,
+    RusshLinux<H>: LinuxExecutor + Clone,
+{
+    let (tx, rx) = mpsc::unbounded_channel();
+    let kind_filter = options.kinds();
+
+    if has_inotifywait(linux).await {
+        let configuration = inotifywait_configuration(path, options);
+        let process = linux
+            .begin_execute(&configuration)
+            .await
+            .map_err(|error| io::Error::other(format!("{error:?}")))?;
+        tokio::spawn(drive_inotifywait(process, kind_filter, tx));
+    } else {
+        let configuration = polling_configuration(path, options);
+        let linux = linux.clone();
+        tokio::spawn(drive_polling(linux, configuration, kind_filter, tx));
+    }
+
+    Ok(RusshWatchStream { events_rx: rx })
+}
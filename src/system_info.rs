@@ -0,0 +1,36 @@
+use std::{io, path::PathBuf};
+
+use async_trait::async_trait;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinuxSystemInfo {
+    pub family: String,
+    pub os: String,
+    pub arch: String,
+    pub current_dir: PathBuf,
+    pub main_separator: char,
+    pub username: String,
+    pub shell: String,
+}
+
+/// Indicates which optional subsystems the current connection actually supports, so callers can
+/// branch instead of discovering `UnsupportedOperation` at call time.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LinuxCapabilities {
+    pub reverse_forward_tcp: bool,
+    pub pty: bool,
+    pub watch: bool,
+    pub search: bool,
+}
+
+#[async_trait]
+pub trait LinuxDiagnostics {
+    /// Returns the remote host's system info, caching the result after the first call.
+    async fn system_info(&self) -> io::Result<LinuxSystemInfo>;
+
+    /// Probes which optional subsystems this connection actually supports, caching the result
+    /// after the first call. Async because determining this honestly requires talking to the
+    /// remote host (checking for helper binaries, attempting a throwaway PTY/forward request)
+    /// rather than reporting a fixed, connection-agnostic answer.
+    async fn capabilities(&self) -> LinuxCapabilities;
+}
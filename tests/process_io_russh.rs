@@ -0,0 +1,44 @@
+use common::TestData;
+use lhf::executor::{LinuxExecutor, LinuxProcess, LinuxProcessConfiguration};
+
+mod common;
+
+#[tokio::test]
+async fn write_all_to_stdin_flushes_entire_payload() {
+    let test_data = TestData::setup().await;
+    let mut configuration = LinuxProcessConfiguration::new("cat");
+    configuration.redirect_stdin().redirect_stdout().stdin_chunk_size(4);
+
+    let mut process = test_data
+        .implementation
+        .begin_execute(&configuration)
+        .await
+        .expect("Call failed");
+
+    let payload = b"a payload longer than the configured stdin chunk size";
+    process.write_all_to_stdin(payload).await.expect("Call failed");
+    process.close_stdin().await.expect("Call failed");
+
+    let output = process.await_exit_with_output().await.expect("Call failed");
+    assert_eq!(output.stdout.unwrap_or_default(), payload);
+}
+
+#[tokio::test]
+async fn output_capacity_cap_sets_truncated_flag() {
+    let test_data = TestData::setup().await;
+    let mut configuration = LinuxProcessConfiguration::new("sh");
+    configuration
+        .arg("-c")
+        .arg("for i in $(seq 1 2000); do echo line$i; done")
+        .redirect_stdout()
+        .max_output_capacity(64);
+
+    let process = test_data
+        .implementation
+        .begin_execute(&configuration)
+        .await
+        .expect("Call failed");
+
+    let output = process.await_exit_with_output().await.expect("Call failed");
+    assert!(output.truncated, "output larger than max_output_capacity should be marked truncated");
+}
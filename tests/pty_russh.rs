@@ -0,0 +1,56 @@
+use common::TestData;
+use lhf::executor::{LinuxExecutor, LinuxProcess, LinuxProcessConfiguration, LinuxProcessError, LinuxPtySize};
+
+mod common;
+
+fn test_pty_size() -> LinuxPtySize {
+    LinuxPtySize {
+        rows: 24,
+        cols: 80,
+        pixel_width: 0,
+        pixel_height: 0,
+    }
+}
+
+#[tokio::test]
+async fn pty_process_can_be_resized() {
+    let test_data = TestData::setup().await;
+    let mut configuration = LinuxProcessConfiguration::new("cat");
+    configuration.pty(test_pty_size()).redirect_stdin().redirect_stdout();
+
+    let mut process = test_data
+        .implementation
+        .begin_execute(&configuration)
+        .await
+        .expect("Call failed");
+
+    process
+        .resize_pty(LinuxPtySize {
+            rows: 40,
+            cols: 120,
+            pixel_width: 0,
+            pixel_height: 0,
+        })
+        .await
+        .expect("Resize should succeed for a PTY-backed process");
+
+    process.kill().await.expect("Call failed");
+}
+
+#[tokio::test]
+async fn resize_pty_is_unsupported_without_pty() {
+    let test_data = TestData::setup().await;
+    let mut configuration = LinuxProcessConfiguration::new("sleep");
+    configuration.arg("5");
+
+    let mut process = test_data
+        .implementation
+        .begin_execute(&configuration)
+        .await
+        .expect("Call failed");
+
+    let result = process.resize_pty(test_pty_size()).await;
+    assert!(matches!(result, Err(LinuxProcessError::UnsupportedOperation)));
+
+    process.kill().await.expect("Call failed");
+}
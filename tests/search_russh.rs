@@ -0,0 +1,41 @@
+use common::{gen_tmp_path, TestData};
+use futures::StreamExt;
+use lhf::search::{LinuxSearch, LinuxSearchQuery, LinuxSearchTarget};
+
+mod common;
+
+#[tokio::test]
+async fn search_contents_finds_seeded_match() {
+    let test_data = TestData::setup().await;
+    let dir_path = gen_tmp_path();
+    test_data.sftp.create_dir(dir_path.to_string_lossy()).await.unwrap();
+    let file_path = dir_path.join("needle.txt");
+    test_data.sftp.create(file_path.to_string_lossy()).await.unwrap();
+    test_data
+        .sftp
+        .write(file_path.to_string_lossy(), b"hello needle world")
+        .await
+        .unwrap();
+
+    let query = LinuxSearchQuery::new(dir_path.clone(), LinuxSearchTarget::Contents, "needle");
+    let (_id, mut matches) = test_data.implementation.search(&query).await.expect("Call failed");
+
+    let found = matches.next().await.expect("Expected at least one match");
+    assert_eq!(found.path, file_path);
+    assert_eq!(found.line_number, Some(1));
+}
+
+#[tokio::test]
+async fn search_path_matches_by_name() {
+    let test_data = TestData::setup().await;
+    let dir_path = gen_tmp_path();
+    test_data.sftp.create_dir(dir_path.to_string_lossy()).await.unwrap();
+    let file_path = dir_path.join("target-file.txt");
+    test_data.sftp.create(file_path.to_string_lossy()).await.unwrap();
+
+    let query = LinuxSearchQuery::new(dir_path.clone(), LinuxSearchTarget::Path, ".*target-file.*");
+    let (_id, mut matches) = test_data.implementation.search(&query).await.expect("Call failed");
+
+    let found = matches.next().await.expect("Expected at least one match");
+    assert_eq!(found.path, file_path);
+}
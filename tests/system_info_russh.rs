@@ -0,0 +1,19 @@
+use common::TestData;
+use lhf::system_info::LinuxDiagnostics;
+
+mod common;
+
+#[tokio::test]
+async fn system_info_reports_connected_user() {
+    let test_data = TestData::setup().await;
+    let system_info = test_data.implementation.system_info().await.expect("Call failed");
+    assert_eq!(system_info.username, "root");
+    assert_eq!(system_info.main_separator, '/');
+}
+
+#[tokio::test]
+async fn capabilities_reports_search_as_supported() {
+    let test_data = TestData::setup().await;
+    let capabilities = test_data.implementation.capabilities().await;
+    assert!(capabilities.search, "container image ships grep and find");
+}
@@ -0,0 +1,64 @@
+use common::{gen_tmp_path, TestData};
+use futures::StreamExt;
+use lhf::filesystem::{LinuxFileType, LinuxFilesystem, LinuxWalkOptions};
+
+mod common;
+
+#[tokio::test]
+async fn walk_dir_yields_nested_entries_with_depth() {
+    let test_data = TestData::setup().await;
+    let root_path = gen_tmp_path();
+    let child_dir_path = root_path.join("child");
+    let grandchild_file_path = child_dir_path.join("leaf.txt");
+
+    test_data.sftp.create_dir(root_path.to_string_lossy()).await.unwrap();
+    test_data.sftp.create_dir(child_dir_path.to_string_lossy()).await.unwrap();
+    test_data.sftp.create(grandchild_file_path.to_string_lossy()).await.unwrap();
+
+    let entries: Vec<_> = test_data
+        .implementation
+        .walk_dir(&root_path, &LinuxWalkOptions::new())
+        .await
+        .expect("Call failed")
+        .filter_map(|entry| async { entry.ok() })
+        .collect()
+        .await;
+
+    let child_entry = entries
+        .iter()
+        .find(|entry| entry.path() == child_dir_path)
+        .expect("child dir entry missing");
+    assert_eq!(child_entry.file_type(), LinuxFileType::Dir);
+    assert_eq!(child_entry.depth(), 1);
+
+    let leaf_entry = entries
+        .iter()
+        .find(|entry| entry.path() == grandchild_file_path)
+        .expect("leaf file entry missing");
+    assert_eq!(leaf_entry.file_type(), LinuxFileType::File);
+    assert_eq!(leaf_entry.depth(), 2);
+}
+
+#[tokio::test]
+async fn walk_dir_respects_max_depth() {
+    let test_data = TestData::setup().await;
+    let root_path = gen_tmp_path();
+    let child_dir_path = root_path.join("child");
+    let grandchild_file_path = child_dir_path.join("leaf.txt");
+
+    test_data.sftp.create_dir(root_path.to_string_lossy()).await.unwrap();
+    test_data.sftp.create_dir(child_dir_path.to_string_lossy()).await.unwrap();
+    test_data.sftp.create(grandchild_file_path.to_string_lossy()).await.unwrap();
+
+    let entries: Vec<_> = test_data
+        .implementation
+        .walk_dir(&root_path, LinuxWalkOptions::new().max_depth(1))
+        .await
+        .expect("Call failed")
+        .filter_map(|entry| async { entry.ok() })
+        .collect()
+        .await;
+
+    assert!(entries.iter().any(|entry| entry.path() == child_dir_path));
+    assert!(!entries.iter().any(|entry| entry.path() == grandchild_file_path));
+}
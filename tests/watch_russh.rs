@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use common::{gen_tmp_path, TestData};
+use futures::StreamExt;
+use lhf::filesystem::{LinuxChangeKind, LinuxChangeKindSet, LinuxFilesystem, LinuxWatchOptions};
+use tokio::time::timeout;
+
+mod common;
+
+#[tokio::test]
+async fn watch_emits_event_on_file_creation() {
+    let test_data = TestData::setup().await;
+    let dir_path = gen_tmp_path();
+    test_data.sftp.create_dir(dir_path.to_string_lossy()).await.unwrap();
+
+    let mut stream = test_data
+        .implementation
+        .watch(&dir_path, LinuxWatchOptions::new().recursive())
+        .await
+        .expect("Call failed");
+
+    let file_path = dir_path.join("created.txt");
+    test_data.sftp.create(file_path.to_string_lossy()).await.unwrap();
+
+    let event = timeout(Duration::from_secs(5), stream.next())
+        .await
+        .expect("Timed out waiting for event")
+        .expect("Stream ended unexpectedly");
+
+    assert_eq!(event.path, file_path);
+    assert!(event.kinds.contains(LinuxChangeKind::Create));
+}
+
+#[tokio::test]
+async fn watch_kind_filter_excludes_other_kinds() {
+    let test_data = TestData::setup().await;
+    let dir_path = gen_tmp_path();
+    test_data.sftp.create_dir(dir_path.to_string_lossy()).await.unwrap();
+
+    let mut options = LinuxWatchOptions::new();
+    options
+        .recursive()
+        .kind_filter(LinuxChangeKindSet::empty().with(LinuxChangeKind::Remove));
+
+    let mut stream = test_data
+        .implementation
+        .watch(&dir_path, &options)
+        .await
+        .expect("Call failed");
+
+    let file_path = dir_path.join("created.txt");
+    test_data.sftp.create(file_path.to_string_lossy()).await.unwrap();
+
+    assert!(
+        timeout(Duration::from_millis(500), stream.next()).await.is_err(),
+        "Create event should have been filtered out"
+    );
+}